@@ -0,0 +1,150 @@
+use std::sync::Arc;
+use std::time::Duration as StdDuration;
+
+use chrono::{DateTime, Utc};
+use csv::ReaderBuilder;
+use sqlx::{sqlite::SqlitePool, Row};
+
+use futures::stream::StreamExt;
+
+use crate::messaging::MessagingClient;
+
+/// Default interval between individual sends in a broadcast fan-out, to stay
+/// under Twilio's per-number rate limit. Overridable via `BROADCAST_SEND_INTERVAL_MS`.
+const DEFAULT_BROADCAST_SEND_INTERVAL_MS: u64 = 200;
+
+const SCHEDULED_BROADCAST_POLL_INTERVAL: StdDuration = StdDuration::from_secs(30);
+
+/// Interval between individual sends in a broadcast fan-out, read from
+/// `BROADCAST_SEND_INTERVAL_MS` so operators can tune it without a recompile.
+fn broadcast_send_interval() -> StdDuration {
+    let millis = std::env::var("BROADCAST_SEND_INTERVAL_MS")
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(DEFAULT_BROADCAST_SEND_INTERVAL_MS);
+    StdDuration::from_millis(millis)
+}
+
+/// Initialize the `scheduled_broadcasts` table used by the announcement scheduler
+pub(crate) async fn init_broadcast_tables(db_pool: &SqlitePool) {
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS scheduled_broadcasts (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            message TEXT NOT NULL,
+            send_at INTEGER NOT NULL,
+            fired BOOLEAN NOT NULL DEFAULT 0
+        )
+        "#,
+    )
+    .execute(db_pool)
+    .await
+    .expect("Failed to create scheduled_broadcasts table");
+}
+
+/// Send `message` to every phone number in the `messages` table, pausing
+/// between sends per `broadcast_send_interval` to avoid Twilio throttling.
+pub(crate) async fn broadcast_message(
+    db_pool: &SqlitePool,
+    messaging_client: &Arc<dyn MessagingClient>,
+    message: &str,
+) {
+    let send_interval = broadcast_send_interval();
+    let mut rows = sqlx::query("SELECT phone_number FROM messages").fetch(db_pool);
+
+    let mut first = true;
+    while let Some(row) = rows.next().await {
+        let row = row.expect("Failed to read contact row");
+        let phone_number: String = row.get("phone_number");
+
+        if !first {
+            tokio::time::sleep(send_interval).await;
+        }
+        first = false;
+
+        messaging_client.send_message(&phone_number, message).await;
+    }
+}
+
+/// Upsert contacts from an uploaded CSV (`phone_number` column required,
+/// `name`/`lang` optional) into the `messages` table, returning the number imported.
+pub(crate) async fn import_contacts_csv(db_pool: &SqlitePool, csv_bytes: &[u8]) -> usize {
+    let mut reader = ReaderBuilder::new().has_headers(true).from_reader(csv_bytes);
+    let mut imported = 0;
+
+    for record in reader.deserialize::<ContactRecord>().flatten() {
+        sqlx::query(
+            r#"
+            INSERT INTO messages (phone_number, name, lang)
+            VALUES (?, ?, COALESCE(?, 'en-US'))
+            ON CONFLICT(phone_number) DO UPDATE SET
+                name = COALESCE(excluded.name, messages.name),
+                lang = COALESCE(?, messages.lang)
+            "#,
+        )
+        .bind(&record.phone_number)
+        .bind(&record.name)
+        .bind(&record.lang)
+        .bind(&record.lang)
+        .execute(db_pool)
+        .await
+        .expect("Failed to upsert contact");
+
+        imported += 1;
+    }
+
+    imported
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct ContactRecord {
+    phone_number: String,
+    name: Option<String>,
+    lang: Option<String>,
+}
+
+/// Background task that fires scheduled announcements once their `send_at` has passed
+pub(crate) async fn run_scheduled_broadcast_task(
+    db_pool: Arc<SqlitePool>,
+    messaging_client: Arc<dyn MessagingClient>,
+) {
+    let mut interval = tokio::time::interval(SCHEDULED_BROADCAST_POLL_INTERVAL);
+    loop {
+        interval.tick().await;
+        send_due_broadcasts(&db_pool, &messaging_client).await;
+    }
+}
+
+async fn send_due_broadcasts(db_pool: &SqlitePool, messaging_client: &Arc<dyn MessagingClient>) {
+    let mut rows = sqlx::query(
+        "SELECT id, message FROM scheduled_broadcasts WHERE send_at <= ? AND fired = 0",
+    )
+    .bind(Utc::now().timestamp())
+    .fetch(db_pool);
+
+    let mut due = Vec::new();
+    while let Some(row) = rows.next().await {
+        let row = row.expect("Failed to read scheduled broadcast row");
+        due.push((row.get::<i64, _>("id"), row.get::<String, _>("message")));
+    }
+
+    for (id, message) in due {
+        broadcast_message(db_pool, messaging_client, &message).await;
+
+        sqlx::query("UPDATE scheduled_broadcasts SET fired = 1 WHERE id = ?")
+            .bind(id)
+            .execute(db_pool)
+            .await
+            .expect("Failed to mark scheduled broadcast fired");
+    }
+}
+
+/// Schedule a new announcement to be broadcast at `send_at`
+pub(crate) async fn schedule_broadcast(db_pool: &SqlitePool, send_at: DateTime<Utc>, message: &str) {
+    sqlx::query("INSERT INTO scheduled_broadcasts (message, send_at, fired) VALUES (?, ?, 0)")
+        .bind(message)
+        .bind(send_at.timestamp())
+        .execute(db_pool)
+        .await
+        .expect("Failed to schedule broadcast");
+}
@@ -2,11 +2,19 @@ use std::sync::Arc;
 
 use chrono::{DateTime, Duration, Utc};
 use openai::chat::{ChatCompletion, ChatCompletionMessage, ChatCompletionMessageRole};
+use regex::Regex;
 use serde::{Deserialize, Serialize};
 use sqlx::{sqlite::SqlitePool, Row};
 
 use futures::stream::StreamExt;
 
+use crate::commands::{
+    CalcCommand, Command, CommandRegistry, HelpCommand, LangCommand, PingCommand, QuoteCommand,
+    RemindCommand, StatsCommand,
+};
+use crate::localization::{Localizer, DEFAULT_LOCALE};
+use crate::reminders;
+
 const DAILY_MESSAGE_LIMIT: i32 = 10;
 
 /// Struct representing a message for the GPT API
@@ -18,34 +26,85 @@ struct GPTMessage {
 
 /// Struct representing a user in the db
 #[derive(Debug)]
-struct User {
-    phone_number: String,
-    total_received: i32,
-    total_sent: i32,
-    received_today: i32,
+pub(crate) struct User {
+    pub(crate) phone_number: String,
+    pub(crate) total_received: i32,
+    pub(crate) total_sent: i32,
+    pub(crate) received_today: i32,
     messages: Vec<GPTMessage>,
-    last_reset: DateTime<Utc>,
+    pub(crate) last_reset: DateTime<Utc>,
+    /// Result of the user's last `!calc` expression, bound as `ans` in the next one
+    pub(crate) last_eval: Option<f64>,
+    /// Locale used to render replies to this user, e.g. `en-US`
+    pub(crate) lang: String,
 }
 
 pub struct ChatBot {
     db_pool: Arc<SqlitePool>,
+    registry: CommandRegistry,
+    localizer: Arc<Localizer>,
 }
 
 impl ChatBot {
     pub async fn new(connection_string: String) -> Self {
+        let db_pool = Arc::new(Self::init_db(&connection_string).await);
+        reminders::init_reminders_table(&db_pool).await;
+        QuoteCommand::init_table(&db_pool).await;
+
+        let localizer = Arc::new(Localizer::load("locales"));
+
+        let mut registry = CommandRegistry::new();
+        registry.register_command(
+            "!help",
+            Box::new(HelpCommand::new(
+                registry.descriptions_handle(),
+                localizer.clone(),
+            )),
+        );
+        registry.register_command("!stats", Box::new(StatsCommand::new(localizer.clone())));
+        registry.register_command("!remind", Box::new(RemindCommand::new(db_pool.clone())));
+        registry.register_command("!calc", Box::new(CalcCommand));
+        registry.register_command("!lang", Box::new(LangCommand::new(localizer.clone())));
+        registry.register_command("!quote", Box::new(QuoteCommand::new(db_pool.clone())));
+        registry.register_pattern_command(
+            Regex::new(r"(?i)^ping$").unwrap(),
+            Box::new(PingCommand),
+        );
+
         ChatBot {
-            db_pool: Arc::new(Self::init_db(&connection_string).await),
+            db_pool,
+            registry,
+            localizer,
         }
     }
 
+    /// Register an additional command without touching core dispatch.
+    pub fn register_command(&mut self, prefix: &str, command: Box<dyn Command>) {
+        self.registry.register_command(prefix, command);
+    }
+
+    /// Shared handle to the bot's database pool, used to wire up background
+    /// tasks (e.g. the reminder scheduler) that live outside `ChatBot`.
+    pub(crate) fn db_pool(&self) -> Arc<SqlitePool> {
+        self.db_pool.clone()
+    }
+
     /// Handle an incoming message from a phone number
     pub async fn handle_message(&self, from: String, message: String) -> String {
         let mut user = self.find_user(&from).await.unwrap();
 
-        if let Some(short_circuit) = Self::handle_short_circuits(&mut user, &message) {
+        if !self.registry.is_quota_exempt(&message) {
+            if let Some(limit_notice) = self.update_quota(&mut user) {
+                self.update_db(&user).await;
+                user.total_sent += 1;
+                return limit_notice;
+            }
+        }
+
+        if let Some(reply) = self.registry.dispatch(&mut user, &message).await {
             self.update_db(&user).await;
             user.total_sent += 1;
-            return short_circuit;
+            return reply;
         }
 
         user.messages.push(GPTMessage {
@@ -56,7 +115,7 @@ impl ChatBot {
         let messages = Self::make_chat_completion_message(&user.messages);
         println!("messages: {:?}", messages);
 
-        let returned_message = Self::get_gpt_response(messages).await;
+        let returned_message = self.get_gpt_response(messages, &user.lang).await;
 
         user.messages.push(GPTMessage {
             role: "System".to_string(),
@@ -69,11 +128,10 @@ impl ChatBot {
         returned_message
     }
 
-    /// Handle special commands without going through GPT
+    /// Reset the daily quota if needed and check whether the user is over it
     ///
-    /// Returns a response if the message is a special command, otherwise None
-    fn handle_short_circuits(user: &mut User, msg: &str) -> Option<String> {
-        // first reset the daily quota if needed
+    /// Returns a response notifying the user if they're over the limit, otherwise None
+    fn update_quota(&self, user: &mut User) -> Option<String> {
         if Utc::now() >= user.last_reset + Duration::days(1) {
             user.received_today = 0;
             user.last_reset = Utc::now();
@@ -82,23 +140,18 @@ impl ChatBot {
         user.received_today += 1;
         user.total_received += 1;
 
-        // check if user is over daily limit
         if user.received_today >= DAILY_MESSAGE_LIMIT {
-            return Some(format!(
-                "You have reached the daily message limit of {}. Your quota will reset at {}",
-                DAILY_MESSAGE_LIMIT,
-                user.last_reset + Duration::days(1)
+            return Some(self.localizer.get(
+                &user.lang,
+                "quota-limit",
+                &[
+                    ("limit", &DAILY_MESSAGE_LIMIT.to_string()),
+                    ("reset", &(user.last_reset + Duration::days(1)).to_string()),
+                ],
             ));
         }
 
-        match msg {
-            "!help" => Some("Commands: !help, !stats".to_string()),
-            "!stats" => Some(format!(
-                "Total messages received: {}, Total messages sent: {}, Messages received today: {}",
-                user.total_received, user.total_sent, user.received_today
-            )),
-            _ => None,
-        }
+        None
     }
 
     /// Initialize the database with the necessary table
@@ -123,9 +176,39 @@ impl ChatBot {
         .await
         .expect("Failed to create table");
 
+        // Columns added to `messages` after its initial release. `CREATE TABLE
+        // IF NOT EXISTS` above is a no-op against a pre-existing database, so
+        // each one needs an explicit, idempotent migration here.
+        Self::ensure_column(&db_pool, "messages", "last_eval", "REAL").await;
+        Self::ensure_column(&db_pool, "messages", "lang", "TEXT NOT NULL DEFAULT 'en-US'").await;
+        Self::ensure_column(&db_pool, "messages", "name", "TEXT").await;
+
         db_pool
     }
 
+    /// Add `column` to `table` if it isn't already present, so upgrading a
+    /// pre-existing database picks up columns introduced by later releases.
+    async fn ensure_column(db_pool: &SqlitePool, table: &str, column: &str, definition: &str) {
+        let columns = sqlx::query(&format!("PRAGMA table_info({})", table))
+            .fetch_all(db_pool)
+            .await
+            .expect("Failed to read table info");
+
+        let exists = columns
+            .iter()
+            .any(|row| row.get::<String, _>("name") == column);
+
+        if !exists {
+            sqlx::query(&format!(
+                "ALTER TABLE {} ADD COLUMN {} {}",
+                table, column, definition
+            ))
+            .execute(db_pool)
+            .await
+            .expect("Failed to add column");
+        }
+    }
+
     /// Convert a slice of GPT messages to a vec of ChatCompletionMessages
     fn make_chat_completion_message(messages: &[GPTMessage]) -> Vec<ChatCompletionMessage> {
         messages
@@ -146,7 +229,7 @@ impl ChatBot {
     }
 
     /// Get a response from the GPT API
-    async fn get_gpt_response(messages: Vec<ChatCompletionMessage>) -> String {
+    async fn get_gpt_response(&self, messages: Vec<ChatCompletionMessage>, lang: &str) -> String {
         let credentials = openai::Credentials::from_env();
         let chat_completion = ChatCompletion::builder("gpt-4o", messages)
             .credentials(credentials)
@@ -164,7 +247,7 @@ impl ChatBot {
                 .trim()
                 .to_string()
         } else {
-            "Failed to get response.".to_string()
+            self.localizer.get(lang, "gpt-error", &[])
         }
     }
 
@@ -177,6 +260,8 @@ impl ChatBot {
             received_today: 0,
             messages: vec![],
             last_reset: Utc::now(),
+            last_eval: None,
+            lang: DEFAULT_LOCALE.to_string(),
         };
 
         let mut rows = sqlx::query("SELECT * FROM messages WHERE phone_number = ?")
@@ -190,13 +275,14 @@ impl ChatBot {
             user.received_today = row.get("received_today");
             user.messages = serde_json::from_str(&row.get::<String, _>("messages")).unwrap();
             user.last_reset = DateTime::from_timestamp(row.get("last_reset"), 0).unwrap();
+            user.last_eval = row.get("last_eval");
+            user.lang = row.get("lang");
         }
 
         if user.messages.is_empty() {
             user.messages.push(GPTMessage {
                 role: "System".to_string(),
-                content: "You are a helpful assistant. Please keep your responses concise."
-                    .to_string(),
+                content: self.localizer.get(&user.lang, "default-system-prompt", &[]),
             });
         }
 
@@ -207,14 +293,16 @@ impl ChatBot {
     async fn update_db(&self, user: &User) {
         sqlx::query(
             r#"
-            INSERT INTO messages (phone_number, total_received, total_sent, received_today, messages, last_reset)
-            VALUES (?, ?, ?, ?, ?, ?)
+            INSERT INTO messages (phone_number, total_received, total_sent, received_today, messages, last_reset, last_eval, lang)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?)
             ON CONFLICT(phone_number) DO UPDATE SET
                 total_received = excluded.total_received,
                 total_sent = excluded.total_sent,
                 received_today = excluded.received_today,
                 messages = excluded.messages,
-                last_reset = excluded.last_reset
+                last_reset = excluded.last_reset,
+                last_eval = excluded.last_eval,
+                lang = excluded.lang
             "#,
         )
         .bind(&user.phone_number)
@@ -223,6 +311,8 @@ impl ChatBot {
         .bind(user.received_today)
         .bind(serde_json::to_string(&user.messages).unwrap())
         .bind(user.last_reset.timestamp())
+        .bind(user.last_eval)
+        .bind(&user.lang)
         .execute(&*self.db_pool)
         .await
         .expect("Failed to update database");
@@ -0,0 +1,44 @@
+use async_trait::async_trait;
+use meval::Context;
+
+use super::Command;
+use crate::chatbot::User;
+
+const USAGE: &str = "Usage: !calc <expr>, e.g. !calc 2^10 + sqrt(16)";
+
+/// Evaluates arithmetic expressions locally, without spending GPT quota.
+/// The previous result is bound as `ans` so expressions can chain off it.
+pub struct CalcCommand;
+
+#[async_trait]
+impl Command for CalcCommand {
+    fn name(&self) -> &str {
+        "!calc"
+    }
+
+    fn description(&self) -> &str {
+        "Evaluate a math expression: !calc <expr>"
+    }
+
+    fn consumes_quota(&self) -> bool {
+        false
+    }
+
+    async fn execute(&self, user: &mut User, args: &str) -> Option<String> {
+        let expr = args.trim();
+        if expr.is_empty() {
+            return Some(USAGE.to_string());
+        }
+
+        let mut ctx = Context::new();
+        ctx.var("ans", user.last_eval.unwrap_or(0.0));
+
+        match meval::eval_str_with_context(expr, &ctx) {
+            Ok(result) => {
+                user.last_eval = Some(result);
+                Some(result.to_string())
+            }
+            Err(_) => Some(format!("Couldn't evaluate `{}`", expr)),
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use std::sync::{Arc, RwLock};
+
+use async_trait::async_trait;
+
+use super::Command;
+use crate::chatbot::User;
+use crate::localization::Localizer;
+
+/// Lists every registered command and its description.
+pub struct HelpCommand {
+    descriptions: Arc<RwLock<Vec<(String, String)>>>,
+    localizer: Arc<Localizer>,
+}
+
+impl HelpCommand {
+    pub fn new(descriptions: Arc<RwLock<Vec<(String, String)>>>, localizer: Arc<Localizer>) -> Self {
+        HelpCommand {
+            descriptions,
+            localizer,
+        }
+    }
+}
+
+#[async_trait]
+impl Command for HelpCommand {
+    fn name(&self) -> &str {
+        "!help"
+    }
+
+    fn description(&self) -> &str {
+        "List available commands"
+    }
+
+    async fn execute(&self, user: &mut User, _args: &str) -> Option<String> {
+        let commands = self
+            .descriptions
+            .read()
+            .unwrap()
+            .iter()
+            .map(|(name, description)| format!("{} - {}", name, description))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        Some(
+            self.localizer
+                .get(&user.lang, "help", &[("commands", &commands)]),
+        )
+    }
+}
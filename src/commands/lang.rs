@@ -0,0 +1,43 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use super::Command;
+use crate::chatbot::User;
+use crate::localization::Localizer;
+
+/// Sets the locale used to render the user's replies.
+pub struct LangCommand {
+    localizer: Arc<Localizer>,
+}
+
+impl LangCommand {
+    pub fn new(localizer: Arc<Localizer>) -> Self {
+        LangCommand { localizer }
+    }
+}
+
+#[async_trait]
+impl Command for LangCommand {
+    fn name(&self) -> &str {
+        "!lang"
+    }
+
+    fn description(&self) -> &str {
+        "Set your reply language: !lang <code>"
+    }
+
+    async fn execute(&self, user: &mut User, args: &str) -> Option<String> {
+        let code = args.trim();
+        if code.is_empty() {
+            return Some(self.localizer.get(&user.lang, "lang-usage", &[]));
+        }
+
+        if !self.localizer.has_locale(code) {
+            return Some(self.localizer.get(&user.lang, "lang-unknown", &[("lang", code)]));
+        }
+
+        user.lang = code.to_string();
+        Some(self.localizer.get(&user.lang, "lang-set", &[("lang", code)]))
+    }
+}
@@ -0,0 +1,115 @@
+mod calc;
+mod help;
+mod lang;
+mod ping;
+mod quote;
+mod remind;
+mod stats;
+mod version;
+
+pub use calc::CalcCommand;
+pub use help::HelpCommand;
+pub use lang::LangCommand;
+pub use ping::PingCommand;
+pub use quote::QuoteCommand;
+pub use remind::RemindCommand;
+pub use stats::StatsCommand;
+pub use version::VersionCommand;
+
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+use async_trait::async_trait;
+use regex::Regex;
+
+use crate::chatbot::User;
+
+/// A single bot command, triggered either by an exact prefix (e.g. `!stats`)
+/// or by matching a regex pattern against the whole message.
+#[async_trait]
+pub trait Command: Send + Sync {
+    /// Short command name shown in `!help`, e.g. `!stats`.
+    fn name(&self) -> &str;
+
+    /// One-line description shown in `!help`.
+    fn description(&self) -> &str;
+
+    /// Run the command, returning the reply to send back if it handled the message.
+    async fn execute(&self, user: &mut User, args: &str) -> Option<String>;
+
+    /// Whether a message handled by this command counts against the daily
+    /// GPT quota. Override to `false` for commands that never touch GPT,
+    /// e.g. `!calc`.
+    fn consumes_quota(&self) -> bool {
+        true
+    }
+}
+
+/// Registry of bot commands, walked before a message is allowed to fall
+/// through to GPT.
+pub struct CommandRegistry {
+    prefix_commands: HashMap<String, Box<dyn Command>>,
+    pattern_commands: Vec<(Regex, Box<dyn Command>)>,
+    descriptions: Arc<RwLock<Vec<(String, String)>>>,
+}
+
+impl CommandRegistry {
+    pub fn new() -> Self {
+        CommandRegistry {
+            prefix_commands: HashMap::new(),
+            pattern_commands: Vec::new(),
+            descriptions: Arc::new(RwLock::new(Vec::new())),
+        }
+    }
+
+    /// Shared handle to the live list of registered command descriptions,
+    /// used by `HelpCommand` to render `!help`.
+    pub(crate) fn descriptions_handle(&self) -> Arc<RwLock<Vec<(String, String)>>> {
+        self.descriptions.clone()
+    }
+
+    /// Register a command triggered by an exact prefix, e.g. `"!stats"`.
+    pub fn register_command(&mut self, prefix: &str, command: Box<dyn Command>) {
+        self.descriptions.write().unwrap().push((
+            command.name().to_string(),
+            command.description().to_string(),
+        ));
+        self.prefix_commands.insert(prefix.to_string(), command);
+    }
+
+    /// Register a command matched against a regex pattern instead of a fixed prefix.
+    pub fn register_pattern_command(&mut self, pattern: Regex, command: Box<dyn Command>) {
+        self.descriptions.write().unwrap().push((
+            command.name().to_string(),
+            command.description().to_string(),
+        ));
+        self.pattern_commands.push((pattern, command));
+    }
+
+    /// Whether `msg` would be handled by a prefix command that's exempt from
+    /// the daily quota, e.g. `!calc`. Checked before the quota is charged so
+    /// exempt commands never consume it, without executing the command.
+    pub(crate) fn is_quota_exempt(&self, msg: &str) -> bool {
+        let (prefix, _) = msg.split_once(' ').unwrap_or((msg, ""));
+        self.prefix_commands
+            .get(prefix)
+            .is_some_and(|command| !command.consumes_quota())
+    }
+
+    /// Walk the registry for a command matching `msg`, running it if found.
+    pub async fn dispatch(&self, user: &mut User, msg: &str) -> Option<String> {
+        let (prefix, args) = msg.split_once(' ').unwrap_or((msg, ""));
+
+        if let Some(command) = self.prefix_commands.get(prefix) {
+            return command.execute(user, args).await;
+        }
+
+        for (pattern, command) in &self.pattern_commands {
+            if pattern.is_match(msg) {
+                return command.execute(user, msg).await;
+            }
+        }
+
+        None
+    }
+}
@@ -0,0 +1,22 @@
+use async_trait::async_trait;
+
+use super::Command;
+use crate::chatbot::User;
+
+/// Replies to a bare "ping", matched by pattern rather than a `!`-prefix.
+pub struct PingCommand;
+
+#[async_trait]
+impl Command for PingCommand {
+    fn name(&self) -> &str {
+        "ping"
+    }
+
+    fn description(&self) -> &str {
+        "Reply to a bare \"ping\" with \"pong\""
+    }
+
+    async fn execute(&self, _user: &mut User, _args: &str) -> Option<String> {
+        Some("pong".to_string())
+    }
+}
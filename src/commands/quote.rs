@@ -0,0 +1,107 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use sqlx::{sqlite::SqlitePool, Row};
+
+use super::Command;
+use crate::chatbot::User;
+
+const USAGE: &str = "Usage: !quote add <text>, !quote, or !quote <n>";
+
+/// Lets a user save memorable lines and recall them later, without any model calls.
+pub struct QuoteCommand {
+    db_pool: Arc<SqlitePool>,
+}
+
+impl QuoteCommand {
+    pub fn new(db_pool: Arc<SqlitePool>) -> Self {
+        QuoteCommand { db_pool }
+    }
+
+    /// Initialize the `quotes` table used by this command
+    pub async fn init_table(db_pool: &SqlitePool) {
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS quotes (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                phone_number TEXT NOT NULL,
+                text TEXT NOT NULL,
+                added_at INTEGER NOT NULL
+            )
+            "#,
+        )
+        .execute(db_pool)
+        .await
+        .expect("Failed to create quotes table");
+    }
+
+    async fn add_quote(&self, phone_number: &str, text: &str) {
+        sqlx::query("INSERT INTO quotes (phone_number, text, added_at) VALUES (?, ?, ?)")
+            .bind(phone_number)
+            .bind(text)
+            .bind(chrono::Utc::now().timestamp())
+            .execute(&*self.db_pool)
+            .await
+            .expect("Failed to save quote");
+    }
+
+    async fn random_quote(&self, phone_number: &str) -> Option<String> {
+        sqlx::query("SELECT text FROM quotes WHERE phone_number = ? ORDER BY RANDOM() LIMIT 1")
+            .bind(phone_number)
+            .fetch_optional(&*self.db_pool)
+            .await
+            .expect("Failed to fetch random quote")
+            .map(|row| row.get("text"))
+    }
+
+    async fn quote_by_index(&self, phone_number: &str, index: i64) -> Option<String> {
+        sqlx::query("SELECT text FROM quotes WHERE phone_number = ? ORDER BY id LIMIT 1 OFFSET ?")
+            .bind(phone_number)
+            .bind(index - 1)
+            .fetch_optional(&*self.db_pool)
+            .await
+            .expect("Failed to fetch quote by index")
+            .map(|row| row.get("text"))
+    }
+}
+
+#[async_trait]
+impl Command for QuoteCommand {
+    fn name(&self) -> &str {
+        "!quote"
+    }
+
+    fn description(&self) -> &str {
+        "Save or recall a quote: !quote add <text>, !quote, !quote <n>"
+    }
+
+    async fn execute(&self, user: &mut User, args: &str) -> Option<String> {
+        let args = args.trim();
+
+        if let Some(text) = args.strip_prefix("add ") {
+            let text = text.trim();
+            if text.is_empty() {
+                return Some(USAGE.to_string());
+            }
+            self.add_quote(&user.phone_number, text).await;
+            return Some("Quote saved.".to_string());
+        }
+
+        if args.is_empty() {
+            return Some(
+                self.random_quote(&user.phone_number)
+                    .await
+                    .unwrap_or_else(|| "You don't have any saved quotes yet.".to_string()),
+            );
+        }
+
+        match args.parse::<i64>() {
+            Ok(index) if index >= 1 => Some(
+                self.quote_by_index(&user.phone_number, index)
+                    .await
+                    .unwrap_or_else(|| format!("No quote #{}.", index)),
+            ),
+            _ => Some(USAGE.to_string()),
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use sqlx::sqlite::SqlitePool;
+
+use super::Command;
+use crate::chatbot::User;
+use crate::reminders;
+
+const USAGE: &str = "Usage: !remind <when> <text>, e.g. !remind 10m take the pizza out";
+
+/// Schedules a reminder to be texted back to the user later.
+pub struct RemindCommand {
+    db_pool: Arc<SqlitePool>,
+}
+
+impl RemindCommand {
+    pub fn new(db_pool: Arc<SqlitePool>) -> Self {
+        RemindCommand { db_pool }
+    }
+}
+
+#[async_trait]
+impl Command for RemindCommand {
+    fn name(&self) -> &str {
+        "!remind"
+    }
+
+    fn description(&self) -> &str {
+        "Schedule a reminder: !remind <when> <text>"
+    }
+
+    async fn execute(&self, user: &mut User, args: &str) -> Option<String> {
+        match reminders::split_reminder(args) {
+            Some((due_at, body)) if !body.is_empty() => {
+                reminders::schedule_reminder(&self.db_pool, &user.phone_number, due_at, &body)
+                    .await;
+                Some(format!("Got it, I'll remind you at {} UTC", due_at.format("%Y-%m-%d %H:%M")))
+            }
+            _ => Some(USAGE.to_string()),
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use super::Command;
+use crate::chatbot::User;
+use crate::localization::Localizer;
+
+/// Reports the user's message counters.
+pub struct StatsCommand {
+    localizer: Arc<Localizer>,
+}
+
+impl StatsCommand {
+    pub fn new(localizer: Arc<Localizer>) -> Self {
+        StatsCommand { localizer }
+    }
+}
+
+#[async_trait]
+impl Command for StatsCommand {
+    fn name(&self) -> &str {
+        "!stats"
+    }
+
+    fn description(&self) -> &str {
+        "Show your message statistics"
+    }
+
+    async fn execute(&self, user: &mut User, _args: &str) -> Option<String> {
+        Some(self.localizer.get(
+            &user.lang,
+            "stats",
+            &[
+                ("received", &user.total_received.to_string()),
+                ("sent", &user.total_sent.to_string()),
+                ("today", &user.received_today.to_string()),
+            ],
+        ))
+    }
+}
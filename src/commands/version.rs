@@ -0,0 +1,24 @@
+use async_trait::async_trait;
+
+use super::Command;
+use crate::chatbot::User;
+
+/// Example of a command registered via `ChatBot::register_command` instead
+/// of `ChatBot::new`, demonstrating the extension hook without touching
+/// core dispatch.
+pub struct VersionCommand;
+
+#[async_trait]
+impl Command for VersionCommand {
+    fn name(&self) -> &str {
+        "!version"
+    }
+
+    fn description(&self) -> &str {
+        "Show the bot's version"
+    }
+
+    async fn execute(&self, _user: &mut User, _args: &str) -> Option<String> {
+        Some(env!("CARGO_PKG_VERSION").to_string())
+    }
+}
@@ -0,0 +1,79 @@
+use std::collections::HashMap;
+use std::fs;
+
+/// Locale used when a user's stored locale is missing a message key
+pub(crate) const DEFAULT_LOCALE: &str = "en-US";
+
+/// Loads per-language message catalogs from bundled `.ftl`-style files and
+/// renders them with `{placeholder}` interpolation, falling back to
+/// [`DEFAULT_LOCALE`] for missing keys.
+pub(crate) struct Localizer {
+    catalogs: HashMap<String, HashMap<String, String>>,
+}
+
+impl Localizer {
+    /// Load every `<locale>.ftl` file in `locales_dir` into a catalog keyed by locale
+    pub(crate) fn load(locales_dir: &str) -> Self {
+        let mut catalogs = HashMap::new();
+
+        if let Ok(entries) = fs::read_dir(locales_dir) {
+            for entry in entries.flatten() {
+                let path = entry.path();
+                if path.extension().and_then(|ext| ext.to_str()) != Some("ftl") {
+                    continue;
+                }
+                let Some(locale) = path.file_stem().and_then(|stem| stem.to_str()) else {
+                    continue;
+                };
+                let Ok(contents) = fs::read_to_string(&path) else {
+                    continue;
+                };
+                catalogs.insert(locale.to_string(), Self::parse_catalog(&contents));
+            }
+        }
+
+        Localizer { catalogs }
+    }
+
+    fn parse_catalog(contents: &str) -> HashMap<String, String> {
+        contents
+            .lines()
+            .filter_map(|line| {
+                let line = line.trim();
+                if line.is_empty() || line.starts_with('#') {
+                    return None;
+                }
+                let (key, value) = line.split_once('=')?;
+                Some((key.trim().to_string(), value.trim().to_string()))
+            })
+            .collect()
+    }
+
+    /// Whether a catalog was loaded for `locale`, e.g. to validate `!lang <code>`
+    pub(crate) fn has_locale(&self, locale: &str) -> bool {
+        self.catalogs.contains_key(locale)
+    }
+
+    /// Render `key` for `locale`, interpolating `{name}` placeholders from `vars`.
+    ///
+    /// Falls back to [`DEFAULT_LOCALE`] if `locale` or the key is missing there,
+    /// and to the bare key if it's missing from both.
+    pub(crate) fn get(&self, locale: &str, key: &str, vars: &[(&str, &str)]) -> String {
+        let template = self
+            .catalogs
+            .get(locale)
+            .and_then(|catalog| catalog.get(key))
+            .or_else(|| {
+                self.catalogs
+                    .get(DEFAULT_LOCALE)
+                    .and_then(|catalog| catalog.get(key))
+            });
+
+        let mut rendered = template.cloned().unwrap_or_else(|| key.to_string());
+        for (name, value) in vars {
+            rendered = rendered.replace(&format!("{{{}}}", name), value);
+        }
+
+        rendered
+    }
+}
@@ -1,20 +1,33 @@
+mod broadcast;
 mod chatbot;
+mod commands;
+mod localization;
+mod messaging;
+mod reminders;
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use chrono::DateTime;
+use subtle::ConstantTimeEq;
 
 use crate::chatbot::ChatBot;
+use crate::commands::VersionCommand;
+use crate::messaging::MessagingClient;
 use axum::{
-    extract::{Form, State},
+    extract::{Form, Multipart, State},
+    http::{HeaderMap, StatusCode},
     response::IntoResponse,
     routing::post,
-    Router,
+    Json, Router,
 };
 use reqwest::Client;
 use serde::Deserialize;
 
 const DB_STRING: &str = "sqlite:messages.db";
 
+/// Header carrying the shared secret that authenticates admin broadcast requests
+const BROADCAST_SECRET_HEADER: &str = "x-broadcast-secret";
+
 /// Struct representing the webhook sent by Twilio when a message is received
 #[allow(non_snake_case)]
 #[derive(Deserialize)]
@@ -23,13 +36,6 @@ struct TwilioWebhook {
     Body: String, // message body
 }
 
-/// Trait representing a messaging client that can send and receive messages
-#[async_trait]
-trait MessagingClient: Send + Sync {
-    async fn send_message(&self, phone_number: &str, message: &str);
-    async fn receive_message(&self, phone_number: &str, message: &str);
-}
-
 /// Struct for a client using Twilio's API for SMS
 struct TwilioSMSClient {
     account_sid: String,
@@ -107,20 +113,133 @@ async fn handle_sms(
         .await;
 }
 
+/// Shared state for the operator-facing broadcast routes
+#[derive(Clone)]
+struct BroadcastState {
+    db_pool: Arc<sqlx::sqlite::SqlitePool>,
+    messaging_client: Arc<dyn MessagingClient>,
+    shared_secret: Arc<String>,
+}
+
+fn is_authorized(headers: &HeaderMap, shared_secret: &str) -> bool {
+    let Some(provided) = headers
+        .get(BROADCAST_SECRET_HEADER)
+        .and_then(|value| value.to_str().ok())
+    else {
+        return false;
+    };
+
+    // Constant-time compare: this header gates destructive admin routes
+    // (mass broadcast, CSV-driven upserts into the user table).
+    provided.len() == shared_secret.len()
+        && provided.as_bytes().ct_eq(shared_secret.as_bytes()).into()
+}
+
+/// `POST /broadcast` - send a message to every known phone number
+async fn handle_broadcast(
+    State(state): State<BroadcastState>,
+    headers: HeaderMap,
+    body: String,
+) -> impl IntoResponse {
+    if !is_authorized(&headers, &state.shared_secret) {
+        return (StatusCode::UNAUTHORIZED, "unauthorized".to_string());
+    }
+
+    broadcast::broadcast_message(&state.db_pool, &state.messaging_client, &body).await;
+
+    (StatusCode::OK, "broadcast sent".to_string())
+}
+
+/// `POST /broadcast/contacts` - bulk import contacts from an uploaded CSV
+async fn handle_contacts_import(
+    State(state): State<BroadcastState>,
+    headers: HeaderMap,
+    mut multipart: Multipart,
+) -> impl IntoResponse {
+    if !is_authorized(&headers, &state.shared_secret) {
+        return (StatusCode::UNAUTHORIZED, "unauthorized".to_string());
+    }
+
+    let Ok(Some(field)) = multipart.next_field().await else {
+        return (StatusCode::BAD_REQUEST, "missing csv file".to_string());
+    };
+    let Ok(data) = field.bytes().await else {
+        return (StatusCode::BAD_REQUEST, "failed to read csv file".to_string());
+    };
+
+    let imported = broadcast::import_contacts_csv(&state.db_pool, &data).await;
+
+    (StatusCode::OK, format!("imported {} contacts", imported))
+}
+
+/// Request body for `POST /broadcast/schedule`
+#[derive(Deserialize)]
+struct ScheduleBroadcastRequest {
+    send_at: i64, // unix timestamp
+    message: String,
+}
+
+/// `POST /broadcast/schedule` - queue a message to be broadcast at a later time
+async fn handle_schedule_broadcast(
+    State(state): State<BroadcastState>,
+    headers: HeaderMap,
+    Json(request): Json<ScheduleBroadcastRequest>,
+) -> impl IntoResponse {
+    if !is_authorized(&headers, &state.shared_secret) {
+        return (StatusCode::UNAUTHORIZED, "unauthorized".to_string());
+    }
+
+    let Some(send_at) = DateTime::from_timestamp(request.send_at, 0) else {
+        return (StatusCode::BAD_REQUEST, "invalid send_at".to_string());
+    };
+
+    broadcast::schedule_broadcast(&state.db_pool, send_at, &request.message).await;
+
+    (StatusCode::OK, "broadcast scheduled".to_string())
+}
+
 #[tokio::main]
 async fn main() {
     dotenv::dotenv().ok(); // load .env file
 
+    let mut chatbot = ChatBot::new(DB_STRING.to_string()).await;
+    chatbot.register_command("!version", Box::new(VersionCommand));
+    let chatbot = Arc::new(chatbot);
+    broadcast::init_broadcast_tables(&chatbot.db_pool()).await;
+
     let messaging_client = Arc::new(TwilioSMSClient::new(
-        Arc::new(ChatBot::new(DB_STRING.to_string()).await),
+        chatbot.clone(),
         std::env::var("TWILIO_ACCOUNT_SID").unwrap(),
         std::env::var("TWILIO_AUTH_TOKEN").unwrap(),
         std::env::var("TWILIO_PHONE_NUMBER").unwrap(),
     ));
 
+    tokio::spawn(reminders::run_reminder_scheduler(
+        chatbot.db_pool(),
+        messaging_client.clone(),
+    ));
+
+    tokio::spawn(broadcast::run_scheduled_broadcast_task(
+        chatbot.db_pool(),
+        messaging_client.clone(),
+    ));
+
+    let broadcast_state = BroadcastState {
+        db_pool: chatbot.db_pool(),
+        messaging_client: messaging_client.clone(),
+        shared_secret: Arc::new(std::env::var("BROADCAST_SHARED_SECRET").unwrap()),
+    };
+
     let app = Router::new()
         .route("/sms", post(handle_sms))
-        .with_state(messaging_client);
+        .with_state(messaging_client)
+        .merge(
+            Router::new()
+                .route("/broadcast", post(handle_broadcast))
+                .route("/broadcast/contacts", post(handle_contacts_import))
+                .route("/broadcast/schedule", post(handle_schedule_broadcast))
+                .with_state(broadcast_state),
+        );
 
     let listener = tokio::net::TcpListener::bind("0.0.0.0:3000").await.unwrap();
     axum::serve(listener, app).await.unwrap();
@@ -0,0 +1,8 @@
+use async_trait::async_trait;
+
+/// Trait representing a messaging client that can send and receive messages
+#[async_trait]
+pub(crate) trait MessagingClient: Send + Sync {
+    async fn send_message(&self, phone_number: &str, message: &str);
+    async fn receive_message(&self, phone_number: &str, message: &str);
+}
@@ -0,0 +1,176 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Duration, Utc};
+use regex::Regex;
+use sqlx::{sqlite::SqlitePool, Row};
+
+use futures::stream::StreamExt;
+
+use crate::messaging::MessagingClient;
+
+const REMINDER_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(30);
+
+/// Default clock time used when `!remind today`/`!remind tomorrow` is given
+/// without an explicit time.
+const DEFAULT_REMINDER_HOUR: u32 = 9;
+
+/// Initialize the `reminders` table used by the `!remind` command
+pub(crate) async fn init_reminders_table(db_pool: &SqlitePool) {
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS reminders (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            phone_number TEXT NOT NULL,
+            due_at INTEGER NOT NULL,
+            body TEXT NOT NULL,
+            fired BOOLEAN NOT NULL DEFAULT 0
+        )
+        "#,
+    )
+    .execute(db_pool)
+    .await
+    .expect("Failed to create reminders table");
+}
+
+/// Parse the `<when>` portion of `!remind <when> <text>` into an absolute UTC time.
+///
+/// Accepts relative durations (`10m`, `2h`, `1d30m`) and absolute day-keywords
+/// (`today`/`tomorrow`, optionally followed by a `HH(:MM)?(am|pm)?` clock time).
+/// There is no per-user timezone stored anywhere in the bot, so day-keywords
+/// and clock times are always interpreted as UTC, not a user's local time.
+fn parse_when(input: &str) -> Option<DateTime<Utc>> {
+    if let Some(duration) = parse_relative_duration(input) {
+        return Some(Utc::now() + duration);
+    }
+
+    parse_absolute_day(input)
+}
+
+/// Split `!remind <when> <text>` into a parsed due time and the remaining body.
+///
+/// `<when>` can be a single token (`10m`, `today`) or, for an absolute day
+/// keyword followed by a clock time (`tomorrow 9am`), two tokens — so this
+/// tries the longest leading span of `args` first rather than always
+/// cutting at the first space, which would otherwise strand the clock time
+/// inside the reminder body.
+pub(crate) fn split_reminder(args: &str) -> Option<(DateTime<Utc>, String)> {
+    let words: Vec<&str> = args.split_whitespace().collect();
+    let max_when_len = words.len().min(2);
+
+    for when_len in (1..=max_when_len).rev() {
+        let when = words[..when_len].join(" ");
+        if let Some(due_at) = parse_when(&when) {
+            return Some((due_at, words[when_len..].join(" ")));
+        }
+    }
+
+    None
+}
+
+fn parse_relative_duration(input: &str) -> Option<Duration> {
+    let compact: String = input.chars().filter(|c| !c.is_whitespace()).collect();
+    let whole = Regex::new(r"(?i)^(?:\d+[smhd])+$").unwrap();
+    if compact.is_empty() || !whole.is_match(&compact) {
+        return None;
+    }
+
+    let part = Regex::new(r"(?i)(\d+)([smhd])").unwrap();
+    let mut total = Duration::zero();
+    for cap in part.captures_iter(&compact) {
+        let amount: i64 = cap[1].parse().ok()?;
+        total += match cap[2].to_lowercase().as_str() {
+            "s" => Duration::seconds(amount),
+            "m" => Duration::minutes(amount),
+            "h" => Duration::hours(amount),
+            "d" => Duration::days(amount),
+            _ => unreachable!(),
+        };
+    }
+
+    Some(total)
+}
+
+fn parse_absolute_day(input: &str) -> Option<DateTime<Utc>> {
+    let re =
+        Regex::new(r"(?i)^(today|tomorrow)(?:\s+(\d{1,2})(?::(\d{2}))?\s*(am|pm)?)?$").unwrap();
+    let caps = re.captures(input.trim())?;
+
+    let mut date = Utc::now().date_naive();
+    if caps[1].eq_ignore_ascii_case("tomorrow") {
+        date += Duration::days(1);
+    }
+
+    let (hour, minute) = match caps.get(2) {
+        Some(hour_match) => {
+            let mut hour: u32 = hour_match.as_str().parse().ok()?;
+            let minute: u32 = match caps.get(3) {
+                Some(minute_match) => minute_match.as_str().parse().ok()?,
+                None => 0,
+            };
+            if let Some(meridiem) = caps.get(4) {
+                hour %= 12;
+                if meridiem.as_str().eq_ignore_ascii_case("pm") {
+                    hour += 12;
+                }
+            }
+            (hour, minute)
+        }
+        None => (DEFAULT_REMINDER_HOUR, 0),
+    };
+
+    date.and_hms_opt(hour, minute, 0)
+        .map(|naive| naive.and_utc())
+}
+
+/// Insert a new reminder row for `phone_number`, due at `due_at`
+pub(crate) async fn schedule_reminder(
+    db_pool: &SqlitePool,
+    phone_number: &str,
+    due_at: DateTime<Utc>,
+    body: &str,
+) {
+    sqlx::query(
+        "INSERT INTO reminders (phone_number, due_at, body, fired) VALUES (?, ?, ?, 0)",
+    )
+    .bind(phone_number)
+    .bind(due_at.timestamp())
+    .bind(body)
+    .execute(db_pool)
+    .await
+    .expect("Failed to schedule reminder");
+}
+
+/// Background task that periodically sends and marks off due reminders
+pub(crate) async fn run_reminder_scheduler(
+    db_pool: Arc<SqlitePool>,
+    messaging_client: Arc<dyn MessagingClient>,
+) {
+    let mut interval = tokio::time::interval(REMINDER_POLL_INTERVAL);
+    loop {
+        interval.tick().await;
+        send_due_reminders(&db_pool, &messaging_client).await;
+    }
+}
+
+async fn send_due_reminders(db_pool: &SqlitePool, messaging_client: &Arc<dyn MessagingClient>) {
+    let mut rows = sqlx::query("SELECT id, phone_number, body FROM reminders WHERE due_at <= ? AND fired = 0")
+        .bind(Utc::now().timestamp())
+        .fetch(db_pool);
+
+    while let Some(row) = rows.next().await {
+        let row = row.expect("Failed to read reminder row");
+        let id: i64 = row.get("id");
+        let phone_number: String = row.get("phone_number");
+        let body: String = row.get("body");
+
+        messaging_client
+            .send_message(&phone_number, &format!("Reminder: {}", body))
+            .await;
+
+        sqlx::query("UPDATE reminders SET fired = 1 WHERE id = ?")
+            .bind(id)
+            .execute(db_pool)
+            .await
+            .expect("Failed to mark reminder fired");
+    }
+}